@@ -0,0 +1,102 @@
+use super::{LearningRate, LrScheduler};
+
+/// Chains several schedulers one after another, switching to the next phase
+/// once its configured number of iterations has elapsed.
+///
+/// A typical build composes a linear warmup, a cyclical main phase and a
+/// linear cooldown into a single scheduler: three schedulers with
+/// `durations = [warmup_iters, main_iters]`, the cooldown phase running for
+/// the remainder of training.
+pub struct ConcatScheduler {
+    schedulers: Vec<Box<dyn LrScheduler>>,
+    durations: Vec<usize>,
+    current: usize,
+    iteration: usize,
+}
+
+impl ConcatScheduler {
+    /// Creates a new scheduler chaining `schedulers` phase by phase.
+    ///
+    /// `durations[i]` is the number of iterations spent in `schedulers[i]`
+    /// before switching to `schedulers[i + 1]`. There must be exactly one more
+    /// scheduler than durations, since the last phase runs for the remainder
+    /// of training.
+    pub fn new(schedulers: Vec<Box<dyn LrScheduler>>, durations: Vec<usize>) -> Self {
+        assert_eq!(
+            schedulers.len(),
+            durations.len() + 1,
+            "there should be exactly one more scheduler than durations"
+        );
+
+        Self {
+            schedulers,
+            durations,
+            current: 0,
+            iteration: 0,
+        }
+    }
+}
+
+impl LrScheduler for ConcatScheduler {
+    fn step(&mut self) -> LearningRate {
+        if let Some(&duration) = self.durations.get(self.current) {
+            if self.iteration >= duration {
+                self.current += 1;
+                self.iteration = 0;
+            }
+        }
+
+        self.iteration += 1;
+        self.schedulers[self.current].step()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ConstScheduler(LearningRate);
+
+    impl LrScheduler for ConstScheduler {
+        fn step(&mut self) -> LearningRate {
+            self.0
+        }
+    }
+
+    #[test]
+    fn switches_phase_exactly_at_duration_boundary() {
+        let mut scheduler = ConcatScheduler::new(
+            vec![Box::new(ConstScheduler(0.1)), Box::new(ConstScheduler(0.2))],
+            vec![2],
+        );
+
+        let lrs: Vec<LearningRate> = (0..4).map(|_| scheduler.step()).collect();
+
+        assert_eq!(lrs, vec![0.1, 0.1, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn three_phases_switch_in_order() {
+        let mut scheduler = ConcatScheduler::new(
+            vec![
+                Box::new(ConstScheduler(0.1)),
+                Box::new(ConstScheduler(0.2)),
+                Box::new(ConstScheduler(0.3)),
+            ],
+            vec![1, 1],
+        );
+
+        let lrs: Vec<LearningRate> = (0..3).map(|_| scheduler.step()).collect();
+
+        assert_eq!(lrs, vec![0.1, 0.2, 0.3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn requires_exactly_one_more_scheduler_than_durations() {
+        ConcatScheduler::new(
+            vec![Box::new(ConstScheduler(0.1)), Box::new(ConstScheduler(0.2))],
+            vec![1, 2],
+        );
+    }
+}
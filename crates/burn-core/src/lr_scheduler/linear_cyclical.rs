@@ -0,0 +1,69 @@
+use super::{LearningRate, LrScheduler};
+
+/// A scheduler whose learning rate oscillates linearly between `start_value`
+/// and `end_value` following a triangular wave of period `cycle_size`
+/// iterations.
+#[derive(Clone, Debug)]
+pub struct LinearCyclicalScheduler {
+    start_value: LearningRate,
+    end_value: LearningRate,
+    cycle_size: usize,
+    iteration: usize,
+}
+
+impl LinearCyclicalScheduler {
+    /// Creates a new cyclical scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_value` - The learning rate at the start and end of each cycle.
+    /// * `end_value` - The learning rate at the peak of each cycle.
+    /// * `cycle_size` - The number of iterations in a full cycle.
+    pub fn new(start_value: LearningRate, end_value: LearningRate, cycle_size: usize) -> Self {
+        assert!(cycle_size > 0, "cycle_size must be greater than zero");
+
+        Self {
+            start_value,
+            end_value,
+            cycle_size,
+            iteration: 0,
+        }
+    }
+}
+
+impl LrScheduler for LinearCyclicalScheduler {
+    fn step(&mut self) -> LearningRate {
+        let half_cycle = self.cycle_size as f64 / 2.0;
+        let phase = (self.iteration % self.cycle_size) as f64 / half_cycle;
+        let lr = self.end_value + (self.start_value - self.end_value) * (1.0 - phase).abs();
+
+        self.iteration += 1;
+
+        lr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_at_start_value_and_peaks_at_end_value_mid_cycle() {
+        let mut scheduler = LinearCyclicalScheduler::new(0.1, 1.0, 4);
+
+        assert!((scheduler.step() - 0.1).abs() < 1e-9); // t = 0
+        assert!((scheduler.step() - 0.55).abs() < 1e-9); // t = 1, halfway up
+        assert!((scheduler.step() - 1.0).abs() < 1e-9); // t = 2, the peak
+        assert!((scheduler.step() - 0.55).abs() < 1e-9); // t = 3, halfway down
+    }
+
+    #[test]
+    fn repeats_after_cycle_size_iterations() {
+        let mut scheduler = LinearCyclicalScheduler::new(0.0, 1.0, 4);
+
+        let first_cycle: Vec<LearningRate> = (0..4).map(|_| scheduler.step()).collect();
+        let second_cycle: Vec<LearningRate> = (0..4).map(|_| scheduler.step()).collect();
+
+        assert_eq!(first_cycle, second_cycle);
+    }
+}
@@ -0,0 +1,15 @@
+mod concat;
+mod linear_cyclical;
+
+pub use concat::ConcatScheduler;
+pub use linear_cyclical::LinearCyclicalScheduler;
+
+/// A learning rate.
+pub type LearningRate = f64;
+
+/// A learning rate scheduler, driven once per training iteration.
+pub trait LrScheduler: Send + Sync {
+    /// Advances the scheduler by one iteration and returns the learning rate
+    /// to use for it.
+    fn step(&mut self) -> LearningRate;
+}
@@ -1,15 +1,69 @@
 use burn_core::data::dataloader::DataLoader;
+use burn_core::module::{ModuleMapper, ParamId};
+use burn_core::optim::GradientsParams;
 use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::Tensor;
 use burn_core::{
     lr_scheduler::LrScheduler, module::AutodiffModule, optim::GradientsAccumulator,
     tensor::backend::Backend,
 };
-use tracing_macro::scope;
 use std::sync::Arc;
+use tracing_macro::scope;
 
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
-use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
 use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
+use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
+
+/// Scales every tensor registered in `grads` by `1 / divisor`, turning a sum of
+/// gradients (e.g. accumulated across devices or micro-steps) into their mean.
+fn average_gradients<B: AutodiffBackend, M: AutodiffModule<B>>(
+    model: &M,
+    grads: GradientsParams,
+    divisor: f64,
+) -> GradientsParams {
+    struct Averager {
+        grads: GradientsParams,
+        divisor: f64,
+    }
+
+    impl<B: Backend> ModuleMapper<B> for Averager {
+        fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+            if let Some(grad) = self.grads.remove::<B, D>(id) {
+                self.grads
+                    .register::<B, D>(id, grad.div_scalar(self.divisor));
+            }
+
+            tensor
+        }
+    }
+
+    let mut averager = Averager { grads, divisor };
+    model.valid().map(&mut averager);
+    averager.grads
+}
+
+/// Reconfigures a [`TrainEpoch`] at the start of each epoch, enabling
+/// progressive training schedules (e.g. ramping image resolution, gradient
+/// accumulation or model-side regularization across a run) without rebuilding
+/// the [`Learner`](crate::learner::Learner).
+pub trait EpochAdapter<B: AutodiffBackend, TI, M> {
+    /// Builds the configuration to use for `epoch` (1-indexed, out of
+    /// `epoch_total`).
+    fn adapt(&mut self, epoch: usize, epoch_total: usize) -> EpochConfig<B, TI, M>;
+}
+
+/// Configuration produced by an [`EpochAdapter`] and applied at the top of
+/// [`TrainEpoch::run`]/[`TrainEpoch::run_multi_device`].
+pub struct EpochConfig<B: AutodiffBackend, TI, M> {
+    /// The dataloader(s) to use for this epoch, e.g. swapped out for one that
+    /// yields a different input resolution.
+    pub dataloader: Vec<Arc<dyn DataLoader<B, TI>>>,
+    /// The gradient accumulation factor to use for this epoch.
+    pub grad_accumulation: Option<usize>,
+    /// An opaque callback applied to the model before training starts, e.g. to
+    /// adjust dropout or drop-path rates.
+    pub on_model: Option<Box<dyn FnOnce(&mut M)>>,
+}
 
 /// A validation epoch.
 #[derive(new)]
@@ -26,6 +80,24 @@ pub struct TrainEpoch<B: AutodiffBackend, TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    /// When set, [`TrainEpoch::run`] runs a mid-epoch validation pass every
+    /// `eval_interval` training iterations (batches, not optimizer steps when
+    /// gradients are accumulated), in addition to the usual end-of-epoch
+    /// [`ValidEpoch`]. Defaults to `None`, preserving the previous behavior.
+    #[new(default)]
+    eval_interval: Option<usize>,
+    /// When set, [`TrainEpoch::run`] invokes the checkpoint hook every
+    /// `checkpoint_interval` training iterations, in addition to the usual
+    /// end-of-epoch checkpoint. Defaults to `None`, preserving the previous
+    /// behavior.
+    #[new(default)]
+    checkpoint_interval: Option<usize>,
+    /// When `grad_accumulation` is set, whether the accumulated gradients are
+    /// averaged (divided by the number of steps collected) before being
+    /// applied, rather than used as a raw sum. Defaults to `false`, preserving
+    /// the previous (sum) behavior.
+    #[new(value = "false")]
+    average_accumulated_gradients: bool,
 }
 
 impl<B: Backend, VI> ValidEpoch<B, VI> {
@@ -86,6 +158,13 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `mid_epoch_valid` - Called every `eval_interval` steps (if set) to run a
+    ///   capped mid-epoch validation pass. Receives the model (in training mode)
+    ///   and the processor so it can emit the usual validation events.
+    /// * `mid_epoch_checkpoint` - Called every `checkpoint_interval` steps (if
+    ///   set) with the model, optimizer and current iteration.
+    /// * `adapter` - When set, called once at the start of the epoch to apply a
+    ///   progressive training schedule (see [`EpochAdapter`]).
     ///
     /// # Returns
     ///
@@ -98,6 +177,9 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
         scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
         interrupter: &TrainingInterrupter,
+        mut mid_epoch_valid: Option<&mut dyn FnMut(&LC::Model, &mut LC::EventProcessor, usize)>,
+        mut mid_epoch_checkpoint: Option<&mut dyn FnMut(&LC::Model, &LC::Optimizer, usize)>,
+        adapter: Option<&mut dyn EpochAdapter<B, TI, LC::Model>>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -105,15 +187,26 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
     {
         log::info!("Executing training step for epoch {}", self.epoch,);
 
+        if let Some(adapter) = adapter {
+            let config = adapter.adapt(self.epoch, self.epoch_total);
+            self.dataloader = config.dataloader;
+            self.grad_accumulation = config.grad_accumulation;
+
+            if let Some(on_model) = config.on_model {
+                on_model(&mut model);
+            }
+        }
+
         // Single device / dataloader
         let mut iterator = scope!("get dataloader", self.dataloader[0].iter());
         let mut iteration = 0;
+        let mut lr = 0.0;
         let mut accumulator = scope!("new acc", GradientsAccumulator::new());
         let mut accumulation_current = 0;
 
         while let Some(item) = scope!("iter.next", iterator.next()) {
             iteration += 1;
-            let lr = scheduler.step();
+            lr = scheduler.step();
             log::info!("Iteration {iteration}");
 
             let progress = scope!("progress", iterator.progress());
@@ -126,6 +219,11 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
 
                     if accumulation <= accumulation_current {
                         let grads = scope!("grads", accumulator.grads());
+                        let grads = if self.average_accumulated_gradients {
+                            average_gradients(&model, grads, accumulation_current as f64)
+                        } else {
+                            grads
+                        };
                         model = model.optimize(&mut optim, lr, grads);
                         accumulation_current = 0;
                     }
@@ -133,23 +231,63 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
                 None => model = model.optimize(&mut optim, lr, item.grads),
             }
 
-            let item = scope!("new learner item", LearnerItem::new(
-                item.item,
-                progress,
-                self.epoch,
-                self.epoch_total,
-                iteration,
-                Some(lr),
-            ));
+            let item = scope!(
+                "new learner item",
+                LearnerItem::new(
+                    item.item,
+                    progress,
+                    self.epoch,
+                    self.epoch_total,
+                    iteration,
+                    Some(lr),
+                )
+            );
+
+            scope!(
+                "process train 1",
+                processor.process_train(Event::ProcessedItem(item))
+            );
 
-            scope!("process train 1", processor.process_train(Event::ProcessedItem(item)));
+            if let Some(eval_interval) = self.eval_interval {
+                if eval_interval > 0 && iteration % eval_interval == 0 {
+                    if let Some(mid_epoch_valid) = mid_epoch_valid.as_mut() {
+                        log::info!("Running mid-epoch validation at iteration {iteration}");
+                        mid_epoch_valid(&model, processor, iteration);
+                    }
+                }
+            }
+
+            if let Some(checkpoint_interval) = self.checkpoint_interval {
+                if checkpoint_interval > 0 && iteration % checkpoint_interval == 0 {
+                    if let Some(mid_epoch_checkpoint) = mid_epoch_checkpoint.as_mut() {
+                        log::info!("Checkpointing at iteration {iteration}");
+                        mid_epoch_checkpoint(&model, &optim, iteration);
+                    }
+                }
+            }
 
             if interrupter.should_stop() {
                 log::info!("Training interrupted.");
                 break;
             }
         }
-        scope!("process train 2", processor.process_train(Event::EndEpoch(self.epoch)));
+
+        // Flush any residual gradients left over by a partial accumulation
+        // group, whether the epoch ran to completion or was interrupted.
+        if accumulation_current > 0 {
+            let grads = accumulator.grads();
+            let grads = if self.average_accumulated_gradients {
+                average_gradients(&model, grads, accumulation_current as f64)
+            } else {
+                grads
+            };
+            model = model.optimize(&mut optim, lr, grads);
+        }
+
+        scope!(
+            "process train 2",
+            processor.process_train(Event::EndEpoch(self.epoch))
+        );
 
         self.epoch += 1;
 
@@ -167,6 +305,8 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
     /// * `lr_scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
     /// * `devices` - The devices to use.
+    /// * `adapter` - When set, called once at the start of the epoch to apply a
+    ///   progressive training schedule (see [`EpochAdapter`]).
     ///
     /// # Returns
     ///
@@ -179,6 +319,7 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
         processor: &mut LC::EventProcessor,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
+        adapter: Option<&mut dyn EpochAdapter<B, TI, LC::Model>>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -192,12 +333,26 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
             devices
         );
 
+        if let Some(adapter) = adapter {
+            let config = adapter.adapt(self.epoch, self.epoch_total);
+            self.dataloader = config.dataloader;
+            self.grad_accumulation = config.grad_accumulation;
+
+            if let Some(on_model) = config.on_model {
+                on_model(&mut model);
+            }
+        }
+
         let mut iterators = self.dataloader.iter().map(|d| d.iter()).collect::<Vec<_>>();
         let mut iteration = 0;
+        let mut lr = 0.0;
+        // Accumulates the (already device-averaged) gradient of each synchronized
+        // global step, so that `grad_accumulation` keeps multiplying the global
+        // batch rather than the per-device micro-batch.
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
-        let accumulation = self.grad_accumulation.unwrap_or(1) * devices.len();
+        let accumulation = self.grad_accumulation.unwrap_or(1);
         let step = MultiDevicesTrainStep::new(&devices);
 
         // The main device is always the first in the list.
@@ -210,21 +365,18 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
                 break;
             }
 
-            for item in items {
-                iteration += 1;
-                let lr = lr_scheduler.step();
-
-                // TODO: aggregate multi device (all-reduce)
-                let grads = item.grads.to_device(&device_main, &model);
+            // All items in this batch correspond to the same global iteration: one
+            // micro-batch per device, advanced and scheduled together.
+            iteration += 1;
+            lr = lr_scheduler.step();
 
-                accumulator.accumulate(&model, grads);
-                accumulation_current += 1;
+            let mut device_accumulator = GradientsAccumulator::new();
+            let mut devices_accumulated = 0;
 
-                if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
-                    model = model.optimize(&mut optim, lr, grads);
-                    accumulation_current = 0;
-                }
+            for item in items {
+                let grads = item.grads.to_device(&device_main, &model);
+                device_accumulator.accumulate(&model, grads);
+                devices_accumulated += 1;
 
                 let item = LearnerItem::new(
                     item.item,
@@ -245,8 +397,42 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
             }
 
             if interrupted {
+                // Some devices never reported their gradient for this global step:
+                // discard it rather than all-reducing/optimizing over a gradient
+                // averaged across fewer devices than actually contributed.
                 break;
             }
+
+            // All-reduce: the summed per-device gradients become the mean gradient
+            // for this global step, equivalent to a single large batch.
+            let grads = device_accumulator.grads();
+            let grads = average_gradients(&model, grads, devices_accumulated as f64);
+
+            accumulator.accumulate(&model, grads);
+            accumulation_current += 1;
+
+            if accumulation <= accumulation_current {
+                let grads = accumulator.grads();
+                let grads = if self.average_accumulated_gradients {
+                    average_gradients(&model, grads, accumulation_current as f64)
+                } else {
+                    grads
+                };
+                model = model.optimize(&mut optim, lr, grads);
+                accumulation_current = 0;
+            }
+        }
+
+        // Flush any residual gradients left over by a partial accumulation
+        // group, whether the epoch ran to completion or was interrupted.
+        if accumulation_current > 0 {
+            let grads = accumulator.grads();
+            let grads = if self.average_accumulated_gradients {
+                average_gradients(&model, grads, accumulation_current as f64)
+            } else {
+                grads
+            };
+            model = model.optimize(&mut optim, lr, grads);
         }
 
         processor.process_train(Event::EndEpoch(self.epoch));
@@ -0,0 +1,15 @@
+/// A metric that can be fed a stream of items and queried for its current
+/// aggregated value.
+pub trait Metric<T>: Send {
+    /// The metric's display name, e.g. `"Loss"`.
+    fn name(&self) -> &str;
+
+    /// Feeds a single processed item into the metric's running aggregation.
+    fn update(&mut self, item: &T);
+
+    /// Returns the metric's current aggregated value.
+    fn value(&self) -> f64;
+
+    /// Resets the metric's aggregation, typically called at epoch boundaries.
+    fn clear(&mut self);
+}
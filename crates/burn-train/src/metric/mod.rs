@@ -0,0 +1,8 @@
+mod base;
+pub mod processor;
+mod registry;
+mod usage;
+
+pub use base::Metric;
+pub use registry::Metrics;
+pub use usage::MetricUsage;
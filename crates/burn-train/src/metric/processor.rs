@@ -0,0 +1,69 @@
+use burn_core::data::dataloader::Progress;
+
+use super::Metrics;
+
+/// A single processed item, tagged with enough context (including the
+/// iteration it occurred at) for metrics and dashboards to make sense of it.
+#[derive(new)]
+pub struct LearnerItem<T> {
+    pub item: T,
+    pub progress: Progress,
+    pub epoch: usize,
+    pub epoch_total: usize,
+    pub iteration: usize,
+    pub lr: Option<f64>,
+}
+
+/// A training or validation event, carrying the processed item or marking the
+/// end of an epoch.
+pub enum Event<T> {
+    ProcessedItem(LearnerItem<T>),
+    EndEpoch(usize),
+}
+
+/// Reacts to training/validation events, typically by feeding registered
+/// metrics and a dashboard.
+pub trait EventProcessor {
+    type ItemTrain;
+    type ItemValid;
+
+    fn process_train(&mut self, event: Event<Self::ItemTrain>);
+    fn process_valid(&mut self, event: Event<Self::ItemValid>);
+}
+
+/// The default [`EventProcessor`]: drives a [`Metrics`] registry for the
+/// training and validation streams, updating every metric with each item and
+/// consulting its [`MetricUsage`](super::MetricUsage) against the event's
+/// tagged iteration to decide whether its value is emitted.
+#[derive(new, Default)]
+pub struct FullEventProcessor<TO, VO> {
+    metrics_train: Metrics<TO>,
+    metrics_valid: Metrics<VO>,
+}
+
+impl<TO, VO> EventProcessor for FullEventProcessor<TO, VO> {
+    type ItemTrain = TO;
+    type ItemValid = VO;
+
+    fn process_train(&mut self, event: Event<TO>) {
+        match event {
+            Event::ProcessedItem(item) => {
+                self.metrics_train.update(&item.item, item.iteration);
+            }
+            Event::EndEpoch(_) => {
+                self.metrics_train.end_epoch();
+            }
+        }
+    }
+
+    fn process_valid(&mut self, event: Event<VO>) {
+        match event {
+            Event::ProcessedItem(item) => {
+                self.metrics_valid.update(&item.item, item.iteration);
+            }
+            Event::EndEpoch(_) => {
+                self.metrics_valid.end_epoch();
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::{Metric, MetricUsage};
+
+/// Holds the metrics registered for a training or validation stream, each
+/// tagged with the [`MetricUsage`] controlling how often its value is
+/// emitted, so a costly metric doesn't have to pay its reporting cost on
+/// every iteration.
+#[derive(Default)]
+pub struct Metrics<T> {
+    entries: Vec<(Box<dyn Metric<T>>, MetricUsage)>,
+}
+
+impl<T> Metrics<T> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registers `metric` with the cadence its value should be emitted at.
+    pub fn register(&mut self, metric: Box<dyn Metric<T>>, usage: MetricUsage) {
+        self.entries.push((metric, usage));
+    }
+
+    /// Feeds `item` to every registered metric, regardless of its usage, so
+    /// that cumulative aggregations (like an `EpochWise` metric's running
+    /// sum) never miss data. Returns the `(name, value)` pairs of only the
+    /// metrics whose usage says they should emit at `iteration`.
+    pub fn update(&mut self, item: &T, iteration: usize) -> Vec<(String, f64)> {
+        self.entries
+            .iter_mut()
+            .filter_map(|(metric, usage)| {
+                metric.update(item);
+
+                if usage.should_emit(iteration) {
+                    Some((metric.name().to_string(), metric.value()))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Called at the end of an epoch: emits and clears every registered
+    /// metric, so `EpochWise` metrics (which only emit here) finally report
+    /// their value.
+    pub fn end_epoch(&mut self) -> Vec<(String, f64)> {
+        self.entries
+            .iter_mut()
+            .map(|(metric, _)| {
+                let value = (metric.name().to_string(), metric.value());
+                metric.clear();
+                value
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct SumMetric {
+        total: f64,
+    }
+
+    impl Metric<f64> for SumMetric {
+        fn name(&self) -> &str {
+            "sum"
+        }
+
+        fn update(&mut self, item: &f64) {
+            self.total += *item;
+        }
+
+        fn value(&self) -> f64 {
+            self.total
+        }
+
+        fn clear(&mut self) {
+            self.total = 0.0;
+        }
+    }
+
+    #[test]
+    fn epoch_wise_metric_still_ingests_every_item_and_reports_at_epoch_end() {
+        let mut metrics = Metrics::new();
+        metrics.register(Box::new(SumMetric { total: 0.0 }), MetricUsage::EpochWise);
+
+        for (iteration, item) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+            let emitted = metrics.update(&item, iteration + 1);
+
+            assert!(
+                emitted.is_empty(),
+                "EpochWise metrics must not emit mid-epoch"
+            );
+        }
+
+        assert_eq!(metrics.end_epoch(), vec![("sum".to_string(), 6.0)]);
+    }
+
+    #[test]
+    fn every_n_metric_ingests_every_item_but_only_emits_on_boundary() {
+        let mut metrics = Metrics::new();
+        metrics.register(Box::new(SumMetric { total: 0.0 }), MetricUsage::EveryN(2));
+
+        assert!(metrics.update(&1.0, 1).is_empty());
+        assert_eq!(metrics.update(&1.0, 2), vec![("sum".to_string(), 2.0)]);
+    }
+
+    #[test]
+    fn batch_wise_metric_emits_every_iteration() {
+        let mut metrics = Metrics::new();
+        metrics.register(Box::new(SumMetric { total: 0.0 }), MetricUsage::BatchWise);
+
+        assert_eq!(metrics.update(&5.0, 1), vec![("sum".to_string(), 5.0)]);
+    }
+}
@@ -0,0 +1,26 @@
+/// Controls how often a registered metric's value is computed and emitted, so
+/// that expensive metrics don't have to pay their reporting cost on every
+/// iteration. A metric is always fed every item regardless of its usage —
+/// this only gates when its aggregated value is read out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricUsage {
+    /// Emit the metric's value on every iteration.
+    BatchWise,
+    /// Emit the metric's aggregated value every `n` iterations.
+    EveryN(usize),
+    /// Only emit the metric once per epoch, at [`Event::EndEpoch`](crate::metric::processor::Event::EndEpoch).
+    EpochWise,
+}
+
+impl MetricUsage {
+    /// Returns `true` if a metric tagged with this usage should have its
+    /// value computed and emitted at `iteration` (the 1-indexed iteration
+    /// within the current epoch).
+    pub fn should_emit(&self, iteration: usize) -> bool {
+        match self {
+            MetricUsage::BatchWise => true,
+            MetricUsage::EveryN(n) => *n > 0 && iteration % n == 0,
+            MetricUsage::EpochWise => false,
+        }
+    }
+}